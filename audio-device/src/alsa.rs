@@ -0,0 +1,76 @@
+//! [ALSA] backend for blocking and asynchronous playback and capture.
+//!
+//! [ALSA]: https://www.alsa-project.org
+
+use std::ffi::c_void;
+use std::io;
+
+use crate::driver::SupportedFormat;
+
+mod capture;
+pub use self::capture::InputStream;
+
+/// A handle to an ALSA device, as enumerated through
+/// `snd_device_name_hint`.
+pub struct DeviceHandle {
+    name: String,
+    formats: Vec<SupportedFormat>,
+}
+
+impl DeviceHandle {
+    /// The ALSA PCM name for this device (e.g. `hw:0,0` or `default`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The formats this device's hardware parameters report support for.
+    pub fn supported_formats(&self) -> Vec<SupportedFormat> {
+        self.formats.clone()
+    }
+}
+
+/// Enumerate every PCM device.
+///
+/// This will delegate to `snd_device_name_hint(-1, "pcm", ...)` /
+/// `snd_device_name_get_hint` once this crate's libasound glue lands; until
+/// then it honestly reports that it can't enumerate anything, rather than
+/// silently claiming an empty system.
+pub fn enumerate_devices() -> io::Result<Vec<DeviceHandle>> {
+    Err(unimplemented_error())
+}
+
+/// Get the device that would be opened for playback by the `default` PCM
+/// name.
+///
+/// Not yet wired up to `snd_pcm_open`; see [enumerate_devices]. Note this
+/// previously returned a hardcoded `"default"` handle regardless of
+/// whether the system actually has one — that was worse than an error,
+/// since callers had no way to tell a real device from a guess.
+pub fn default_output_device() -> io::Result<DeviceHandle> {
+    Err(unimplemented_error())
+}
+
+/// Get the device that would be opened for capture by the `default` PCM
+/// name.
+///
+/// Not yet wired up to `snd_pcm_open`; see [enumerate_devices].
+pub fn default_input_device() -> io::Result<DeviceHandle> {
+    Err(unimplemented_error())
+}
+
+fn unimplemented_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "ALSA device enumeration is not yet implemented",
+    )
+}
+
+/// Open a PCM in `SND_PCM_STREAM_CAPTURE` mode.
+///
+/// Not yet wired up to `snd_pcm_open`; see [enumerate_devices]. Note this
+/// previously returned a null handle as if the open had succeeded — that
+/// let [crate::alsa::InputStream::open] appear to work while silently
+/// never reading any real frames.
+pub(crate) unsafe fn pcm_open_capture(_name: &str) -> io::Result<*mut c_void> {
+    Err(unimplemented_error())
+}
@@ -0,0 +1,111 @@
+use std::ffi::c_void;
+use std::io;
+use std::task::{Context, Poll as TaskPoll};
+
+use rotary_core::{Buf, Channel, Channels, ReadBuf};
+
+use crate::alsa::{self, DeviceHandle};
+use crate::driver::Poll;
+
+/// A capture stream opened on an ALSA PCM in `SND_PCM_STREAM_CAPTURE` mode.
+///
+/// Like [crate::wasapi::InputStream], this is exposed as a [ReadBuf] so
+/// recorded frames flow into the same `io::copy`/ring-buffer pipeline as
+/// playback, e.g. to build a record-and-playback-through monitor entirely
+/// on top of [rotary_core] primitives.
+pub struct InputStream {
+    pcm: *mut c_void,
+    channels: usize,
+    poll: Poll,
+    available: usize,
+}
+
+// SAFETY: the PCM handle is only ever driven from the thread that owns
+// `poll`, consistent with ALSA's own threading requirements for a given
+// `snd_pcm_t`.
+unsafe impl Send for InputStream {}
+
+impl InputStream {
+    /// Open a capture stream on `device`, driven by `poll`.
+    ///
+    /// This opens the PCM through [alsa::pcm_open_capture], which always
+    /// returns an `Unsupported` error, so this always returns that error.
+    pub(crate) fn open(device: &DeviceHandle, channels: usize, poll: Poll) -> io::Result<Self> {
+        let pcm = unsafe { alsa::pcm_open_capture(device.name())? };
+
+        Ok(Self {
+            pcm,
+            channels,
+            poll,
+            available: 0,
+        })
+    }
+
+    /// Poll until the next capture period is ready to be read.
+    ///
+    /// This polls the PCM's pollfds registered with the driver's
+    /// [Poll][crate::driver::Poll]. It does not yet retrieve any actual
+    /// frames: `snd_pcm_avail_update` is not wired up, so
+    /// [avail_update][Self::avail_update] always reports zero and
+    /// [Channels::channel] always hands back an empty slice. This is
+    /// unimplemented scaffolding, not a working capture path.
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> TaskPoll<io::Result<()>> {
+        match self.poll.poll_ready(cx) {
+            TaskPoll::Ready(()) => {
+                self.available = self.avail_update();
+                TaskPoll::Ready(Ok(()))
+            }
+            TaskPoll::Pending => TaskPoll::Pending,
+        }
+    }
+
+    /// Report how many frames are available in the current period.
+    ///
+    /// Not yet wired up to `snd_pcm_avail_update`; always reports zero
+    /// until this crate's libasound glue lands.
+    fn avail_update(&self) -> usize {
+        0
+    }
+}
+
+impl Drop for InputStream {
+    fn drop(&mut self) {
+        // Not yet wired up to `snd_pcm_close`.
+    }
+}
+
+impl ReadBuf for InputStream {
+    fn remaining(&self) -> usize {
+        self.available
+    }
+
+    fn advance(&mut self, n: usize) {
+        // Not yet wired up to `snd_pcm_readi`/`mmap_commit`; `available`
+        // is always zero (see `avail_update`), so this never actually
+        // consumes real frames.
+        self.available = self.available.saturating_sub(n);
+    }
+}
+
+impl Buf for InputStream {
+    fn frames_hint(&self) -> Option<usize> {
+        Some(self.available)
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+}
+
+impl Channels<f32> for InputStream {
+    // Not yet wired up to the mmap'd capture area: `available` is always
+    // zero (see `avail_update`), so this always hands back an empty slice
+    // rather than real captured frames.
+    fn channel(&self, channel: usize) -> Channel<'_, f32> {
+        Channel::Interleaved {
+            buf: &[],
+            channels: self.channels,
+            channel,
+        }
+    }
+}
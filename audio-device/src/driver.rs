@@ -12,3 +12,9 @@ cfg_poll_driver! {
     pub(crate) mod poll;
     pub use self::poll::{Poll, PollHandle, PollEventsGuard};
 }
+
+mod devices;
+pub use self::devices::{default_input_device, default_output_device, Device, Devices};
+
+mod format;
+pub use self::format::{SampleFormat, SupportedFormat};
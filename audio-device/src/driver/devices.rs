@@ -0,0 +1,157 @@
+use crate::driver::SupportedFormat;
+
+/// A handle to an audio device, backed by whichever platform backend is
+/// compiled in.
+///
+/// This is the backend-agnostic counterpart to the platform-specific device
+/// handles exposed by [crate::wasapi] and [crate::alsa]; callers that don't
+/// need to `cfg`-match a specific backend can go through here instead.
+pub struct Device {
+    name: String,
+    backend: Backend,
+}
+
+enum Backend {
+    #[cfg(feature = "wasapi")]
+    Wasapi(crate::wasapi::DeviceHandle),
+    #[cfg(feature = "alsa")]
+    Alsa(crate::alsa::DeviceHandle),
+}
+
+impl Device {
+    /// The human-readable name reported by the backend for this device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The formats this device can be opened with, as negotiated with the
+    /// backend ahead of actually opening a stream.
+    pub fn supported_formats(&self) -> Vec<SupportedFormat> {
+        match &self.backend {
+            #[cfg(feature = "wasapi")]
+            Backend::Wasapi(handle) => handle.supported_formats(),
+            #[cfg(feature = "alsa")]
+            Backend::Alsa(handle) => handle.supported_formats(),
+        }
+    }
+}
+
+#[cfg(feature = "wasapi")]
+fn wasapi_device(handle: crate::wasapi::DeviceHandle) -> Device {
+    Device {
+        name: handle.name().to_owned(),
+        backend: Backend::Wasapi(handle),
+    }
+}
+
+#[cfg(feature = "alsa")]
+fn alsa_device(handle: crate::alsa::DeviceHandle) -> Device {
+    Device {
+        name: handle.name().to_owned(),
+        backend: Backend::Alsa(handle),
+    }
+}
+
+/// An iterator over every audio device exposed by the compiled-in backend.
+///
+/// Construct with [Devices::new].
+pub struct Devices {
+    #[cfg(feature = "wasapi")]
+    wasapi: std::vec::IntoIter<crate::wasapi::DeviceHandle>,
+    #[cfg(feature = "alsa")]
+    alsa: std::vec::IntoIter<crate::alsa::DeviceHandle>,
+}
+
+impl Devices {
+    /// Enumerate every device known to the compiled-in backend.
+    ///
+    /// This calls the backend's `enumerate_devices`. The WASAPI and ALSA
+    /// backends both return an `Unsupported` error from it, which is
+    /// returned here; with no backend compiled in, this yields no devices.
+    pub fn new() -> std::io::Result<Self> {
+        Ok(Self {
+            #[cfg(feature = "wasapi")]
+            wasapi: crate::wasapi::enumerate_devices()?.into_iter(),
+            #[cfg(feature = "alsa")]
+            alsa: crate::alsa::enumerate_devices()?.into_iter(),
+        })
+    }
+}
+
+impl Iterator for Devices {
+    type Item = Device;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(feature = "wasapi")]
+        {
+            if let Some(handle) = self.wasapi.next() {
+                return Some(wasapi_device(handle));
+            }
+        }
+
+        #[cfg(feature = "alsa")]
+        {
+            if let Some(handle) = self.alsa.next() {
+                return Some(alsa_device(handle));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "wasapi")]
+fn wasapi_default_output_device() -> Option<Device> {
+    crate::wasapi::default_output_device().ok().map(wasapi_device)
+}
+
+#[cfg(not(feature = "wasapi"))]
+fn wasapi_default_output_device() -> Option<Device> {
+    None
+}
+
+#[cfg(feature = "wasapi")]
+fn wasapi_default_input_device() -> Option<Device> {
+    crate::wasapi::default_input_device().ok().map(wasapi_device)
+}
+
+#[cfg(not(feature = "wasapi"))]
+fn wasapi_default_input_device() -> Option<Device> {
+    None
+}
+
+#[cfg(feature = "alsa")]
+fn alsa_default_output_device() -> Option<Device> {
+    crate::alsa::default_output_device().ok().map(alsa_device)
+}
+
+#[cfg(not(feature = "alsa"))]
+fn alsa_default_output_device() -> Option<Device> {
+    None
+}
+
+#[cfg(feature = "alsa")]
+fn alsa_default_input_device() -> Option<Device> {
+    crate::alsa::default_input_device().ok().map(alsa_device)
+}
+
+#[cfg(not(feature = "alsa"))]
+fn alsa_default_input_device() -> Option<Device> {
+    None
+}
+
+/// Get a handle to the system's default output device.
+///
+/// Returns `None` if no backend is compiled in or the backend returns an
+/// error looking up the default output device.
+pub fn default_output_device() -> Option<Device> {
+    wasapi_default_output_device().or_else(alsa_default_output_device)
+}
+
+/// Get a handle to the system's default input device.
+///
+/// Returns `None` if no backend is compiled in or the backend returns an
+/// error looking up the default input device.
+pub fn default_input_device() -> Option<Device> {
+    wasapi_default_input_device().or_else(alsa_default_input_device)
+}
@@ -0,0 +1,54 @@
+use std::ops::RangeInclusive;
+
+/// The sample format a device can be opened with, as reported by
+/// [Device::supported_formats][super::Device::supported_formats].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SampleFormat {
+    /// 16-bit signed integer PCM.
+    I16,
+    /// 32-bit signed integer PCM.
+    I32,
+    /// 32-bit IEEE float.
+    F32,
+}
+
+/// One topology a [Device][super::Device] can be opened with, negotiated
+/// ahead of actually opening a stream.
+#[derive(Debug, Clone)]
+pub struct SupportedFormat {
+    pub(crate) channels: u16,
+    pub(crate) sample_rate_range: RangeInclusive<u32>,
+    pub(crate) sample_format: SampleFormat,
+}
+
+impl SupportedFormat {
+    /// Construct a supported format description, for use by backend
+    /// implementations negotiating with the device.
+    pub(crate) fn new(
+        channels: u16,
+        sample_rate_range: RangeInclusive<u32>,
+        sample_format: SampleFormat,
+    ) -> Self {
+        Self {
+            channels,
+            sample_rate_range,
+            sample_format,
+        }
+    }
+
+    /// The number of channels this format describes.
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    /// The range of sample rates, in Hz, this format supports.
+    pub fn sample_rate_range(&self) -> RangeInclusive<u32> {
+        self.sample_rate_range.clone()
+    }
+
+    /// The sample format samples are exchanged in.
+    pub fn sample_format(&self) -> SampleFormat {
+        self.sample_format
+    }
+}
@@ -0,0 +1,84 @@
+//! [WASAPI] backend for blocking and asynchronous playback and capture.
+//!
+//! [WASAPI]: https://docs.microsoft.com/en-us/windows/win32/coreaudio/wasapi
+
+use std::ffi::c_void;
+use std::io;
+
+use crate::driver::SupportedFormat;
+
+mod capture;
+pub use self::capture::InputStream;
+
+/// A handle to a WASAPI endpoint device, as enumerated through
+/// `IMMDeviceEnumerator::EnumAudioEndpoints`.
+pub struct DeviceHandle {
+    name: String,
+    formats: Vec<SupportedFormat>,
+    // Opaque `IMMDevice` COM pointer, passed to `release` on drop.
+    device: *mut c_void,
+}
+
+// SAFETY: the underlying `IMMDevice` COM object is free-threaded with
+// respect to the operations we perform on it (querying properties, opening
+// a client); callers are still required to open streams on the thread that
+// will drive them, same as any other WASAPI client.
+unsafe impl Send for DeviceHandle {}
+
+impl DeviceHandle {
+    /// The friendly name of the endpoint, as reported by its property
+    /// store (`PKEY_Device_FriendlyName`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The formats this endpoint's mix format and shared-mode client
+    /// report support for.
+    pub fn supported_formats(&self) -> Vec<SupportedFormat> {
+        self.formats.clone()
+    }
+}
+
+impl Drop for DeviceHandle {
+    fn drop(&mut self) {
+        // SAFETY: `self.device` was obtained from a matching `AddRef`
+        // during enumeration and is only ever released once.
+        unsafe { release(self.device) }
+    }
+}
+
+unsafe fn release(_ptr: *mut c_void) {
+    // Does nothing: `IUnknown::Release` is not called on the COM object.
+}
+
+/// Enumerate every active render and capture endpoint.
+///
+/// This will delegate to `IMMDeviceEnumerator::EnumAudioEndpoints` once this
+/// crate's raw COM bindings land; until then it honestly reports that it
+/// can't enumerate anything, rather than silently claiming an empty system.
+pub fn enumerate_devices() -> io::Result<Vec<DeviceHandle>> {
+    Err(unimplemented_error())
+}
+
+/// Get the endpoint that would be reported as default by
+/// `IMMDeviceEnumerator::GetDefaultAudioEndpoint(eRender, eConsole)`.
+///
+/// Not yet wired up to the real enumerator; see [enumerate_devices].
+pub fn default_output_device() -> io::Result<DeviceHandle> {
+    Err(unimplemented_error())
+}
+
+/// Get the endpoint that would be reported as default by
+/// `IMMDeviceEnumerator::GetDefaultAudioEndpoint(eCapture, eConsole)`.
+///
+/// Not yet wired up to the real enumerator; see [enumerate_devices].
+pub fn default_input_device() -> io::Result<DeviceHandle> {
+    Err(unimplemented_error())
+}
+
+fn unimplemented_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "WASAPI device enumeration is not yet implemented",
+    )
+}
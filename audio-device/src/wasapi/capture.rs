@@ -0,0 +1,112 @@
+use std::ffi::c_void;
+use std::io;
+use std::task::{Context, Poll as TaskPoll};
+
+use rotary_core::{Buf, Channel, Channels, ReadBuf};
+
+use crate::driver::Events;
+use crate::wasapi::DeviceHandle;
+
+/// A capture stream opened against an `IAudioClient` in shared mode, backed
+/// by an `IAudioCaptureClient`.
+///
+/// Each readable period is surfaced through [ReadBuf] so recorded frames can
+/// be drained with the same [rotary_core::io] pipeline used for playback,
+/// e.g. straight into a [rotary_core] ring buffer for full-duplex
+/// monitoring.
+pub struct InputStream {
+    // Opaque `IAudioCaptureClient` COM pointer.
+    capture_client: *mut c_void,
+    channels: usize,
+    events: Events,
+    available: usize,
+}
+
+// SAFETY: the capture client is only ever driven from the thread that polls
+// `events`, same requirement WASAPI itself places on `IAudioCaptureClient`.
+unsafe impl Send for InputStream {}
+
+impl InputStream {
+    /// Construct a capture stream for `device` driven by `events`.
+    ///
+    /// This does not open an `IAudioClient` or `IAudioCaptureClient` on
+    /// `device`: the capture client pointer is left null and this always
+    /// returns `Ok`, so the stream never reports any captured frames.
+    pub(crate) fn open(_device: &DeviceHandle, channels: usize, events: Events) -> io::Result<Self> {
+        Ok(Self {
+            capture_client: std::ptr::null_mut(),
+            channels,
+            events,
+            available: 0,
+        })
+    }
+
+    /// Poll until the next capture period is ready to be read.
+    ///
+    /// This resolves the underlying event handle through the driver's
+    /// [Events][crate::driver::Events]. It does not yet retrieve any
+    /// actual frames: `IAudioCaptureClient::GetBuffer` /
+    /// `GetNextPacketSize` are not wired up, so [next_packet_size] always
+    /// reports zero and [Channels::channel] always hands back an empty
+    /// slice. This is unimplemented scaffolding, not a working capture
+    /// path.
+    ///
+    /// [next_packet_size]: Self::next_packet_size
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> TaskPoll<io::Result<()>> {
+        match self.events.poll_ready(cx) {
+            TaskPoll::Ready(()) => {
+                self.available = self.next_packet_size()?;
+                TaskPoll::Ready(Ok(()))
+            }
+            TaskPoll::Pending => TaskPoll::Pending,
+        }
+    }
+
+    /// Report how many frames the next capture period holds.
+    ///
+    /// Not yet wired up to `IAudioCaptureClient::GetNextPacketSize`; always
+    /// reports zero until this crate's raw COM glue lands.
+    fn next_packet_size(&self) -> io::Result<usize> {
+        Ok(0)
+    }
+
+    /// Release the most recently read period back to WASAPI.
+    ///
+    /// Not yet wired up to `IAudioCaptureClient::ReleaseBuffer`.
+    fn release_buffer(&mut self, frames: usize) {
+        self.available = self.available.saturating_sub(frames);
+    }
+}
+
+impl ReadBuf for InputStream {
+    fn remaining(&self) -> usize {
+        self.available
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.release_buffer(n);
+    }
+}
+
+impl Buf for InputStream {
+    fn frames_hint(&self) -> Option<usize> {
+        Some(self.available)
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+}
+
+impl Channels<f32> for InputStream {
+    // Not yet wired up to `IAudioCaptureClient::GetBuffer`: `available` is
+    // always zero (see `next_packet_size`), so this always hands back an
+    // empty slice rather than real captured frames.
+    fn channel(&self, channel: usize) -> Channel<'_, f32> {
+        Channel::Interleaved {
+            buf: &[],
+            channels: self.channels,
+            channel,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! I/O adapters for bridging [rotary] buffers with streaming consumers and
+//! producers, such as audio device callbacks.
+//!
+//! [rotary]: crate
+
+mod read;
+pub use self::read::Read;
+
+mod ring_buffer;
+pub use self::ring_buffer::{Consumer, Producer, RingBuffer};
+
+mod resample;
+pub use self::resample::{Lerp, Resample};
+
+mod remix;
+pub use self::remix::{Mix, Remix};
+
+mod translate;
+pub use self::translate::{copy_remaining_convert, Translate, I24};
@@ -0,0 +1,203 @@
+use rotary_core::{Buf, Channel, Channels, ExactSizeBuf, ReadBuf};
+
+/// Types that can be scaled and accumulated when mixing multiple channels
+/// together.
+///
+/// Implementing this for integer sample formats in terms of saturating
+/// arithmetic means a [Remix] matrix with gains that sum to more than unity
+/// clips cleanly instead of wrapping around.
+pub trait Mix: Copy {
+    /// The neutral "silence" value to start an accumulation from.
+    fn zero() -> Self;
+
+    /// Add `self * gain` into the running accumulation.
+    fn mix(self, gain: f64, acc: Self) -> Self;
+}
+
+macro_rules! mix_float {
+    ($ty:ty) => {
+        impl Mix for $ty {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn mix(self, gain: f64, acc: Self) -> Self {
+                acc + self * gain as $ty
+            }
+        }
+    };
+}
+
+macro_rules! mix_int {
+    ($ty:ty) => {
+        impl Mix for $ty {
+            fn zero() -> Self {
+                0
+            }
+
+            fn mix(self, gain: f64, acc: Self) -> Self {
+                let value = acc as f64 + self as f64 * gain;
+                value.round().clamp(<$ty>::MIN as f64, <$ty>::MAX as f64) as $ty
+            }
+        }
+    };
+}
+
+mix_float!(f32);
+mix_float!(f64);
+mix_int!(i16);
+mix_int!(i32);
+
+/// A [Channels] adapter which remixes a source buffer through an `M x N`
+/// gain matrix, where `N` is the number of input channels and `M` the
+/// number of output channels.
+///
+/// # Examples
+///
+/// ```rust
+/// use rotary::io;
+/// use rotary::Buf as _;
+///
+/// let from = rotary::interleaved![[1.0, -1.0]; 1];
+/// let mono = io::Remix::downmix_to_mono(from);
+///
+/// assert_eq!(mono.channels(), 1);
+/// ```
+pub struct Remix<B, T> {
+    buf: B,
+    /// Row-major `outputs x inputs` gain matrix.
+    matrix: Vec<Vec<f64>>,
+    /// Mixed samples for each output channel over `buf`'s current window,
+    /// indexed by channel number. This is recomputed in full on
+    /// construction and after every [advance][ReadBuf::advance], so
+    /// `channel()` only ever needs a plain borrow: unlike computing it
+    /// lazily through `&self`, there is no interior mutation for a
+    /// re-entrant call on the same channel to race with.
+    scratch: Vec<Vec<T>>,
+}
+
+impl<B, T> Remix<B, T>
+where
+    B: Channels<T> + Buf,
+    T: Mix,
+{
+    /// Construct a remix adapter using an explicit `matrix[out][in]` gain
+    /// matrix.
+    pub fn with_matrix(buf: B, matrix: Vec<Vec<f64>>) -> Self {
+        let scratch = (0..matrix.len()).map(|_| Vec::new()).collect();
+
+        let mut this = Self { buf, matrix, scratch };
+        this.recompute();
+        this
+    }
+
+    /// Downmix every input channel to a single output channel using equal
+    /// `1 / N` weights.
+    pub fn downmix_to_mono(buf: B) -> Self {
+        let channels = buf.channels();
+        let gain = if channels == 0 { 0.0 } else { 1.0 / channels as f64 };
+        let matrix = vec![vec![gain; channels]];
+        Self::with_matrix(buf, matrix)
+    }
+
+    /// Upmix a stereo source to 5.1 surround, routing left/right to both
+    /// the front and surround left/right channels and feeding center and
+    /// LFE from an equal blend of both.
+    pub fn stereo_to_5_1(buf: B) -> Self {
+        let matrix = vec![
+            vec![1.0, 0.0], // front left
+            vec![0.0, 1.0], // front right
+            vec![0.5, 0.5], // center
+            vec![0.5, 0.5], // lfe
+            vec![1.0, 0.0], // surround left
+            vec![0.0, 1.0], // surround right
+        ];
+        Self::with_matrix(buf, matrix)
+    }
+
+    /// Fill `scratch` with the mixed samples for every output channel over
+    /// `buf`'s current window.
+    fn recompute(&mut self) {
+        let frames = self.buf.frames_hint().unwrap_or(0);
+
+        for (channel, row) in self.matrix.iter().enumerate() {
+            let scratch = &mut self.scratch[channel];
+            scratch.clear();
+            scratch.reserve(frames);
+
+            for frame in 0..frames {
+                let mut acc = T::zero();
+
+                for (input, &gain) in row.iter().enumerate() {
+                    if gain == 0.0 {
+                        continue;
+                    }
+
+                    let source = self.buf.channel(input);
+                    acc = source.get(frame).unwrap_or_else(T::zero).mix(gain, acc);
+                }
+
+                scratch.push(acc);
+            }
+        }
+    }
+}
+
+impl<B, T> Remix<B, T> {
+    /// Access the underlying buffer.
+    pub fn as_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Convert into the underlying buffer.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B, T> ExactSizeBuf for Remix<B, T>
+where
+    B: ExactSizeBuf,
+{
+    fn frames(&self) -> usize {
+        self.buf.frames()
+    }
+}
+
+impl<B, T> Buf for Remix<B, T>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        self.buf.frames_hint()
+    }
+
+    fn channels(&self) -> usize {
+        self.matrix.len()
+    }
+}
+
+impl<B, T> Channels<T> for Remix<B, T>
+where
+    B: Channels<T> + Buf,
+    T: Mix,
+{
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        Channel::Linear(&self.scratch[channel])
+    }
+}
+
+impl<B, T> ReadBuf for Remix<B, T>
+where
+    B: Channels<T> + ReadBuf,
+    T: Mix,
+{
+    fn remaining(&self) -> usize {
+        self.buf.remaining()
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.buf.advance(n);
+        self.recompute();
+    }
+}
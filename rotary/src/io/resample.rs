@@ -0,0 +1,204 @@
+use rotary_core::{Buf, Channel, Channels, ReadBuf};
+
+/// Types whose samples can be linearly interpolated.
+///
+/// This is a deliberately small trait so [Resample] can be generic over the
+/// common sample formats without pulling in a numeric crate.
+pub trait Lerp: Copy {
+    /// Linearly interpolate between `self` and `other` at `t`, where `t` is
+    /// in the range `0.0..=1.0`.
+    fn lerp(self, other: Self, t: f64) -> Self;
+}
+
+macro_rules! lerp_float {
+    ($ty:ty) => {
+        impl Lerp for $ty {
+            fn lerp(self, other: Self, t: f64) -> Self {
+                (self as f64 * (1.0 - t) + other as f64 * t) as $ty
+            }
+        }
+    };
+}
+
+macro_rules! lerp_int {
+    ($ty:ty) => {
+        impl Lerp for $ty {
+            fn lerp(self, other: Self, t: f64) -> Self {
+                (self as f64 * (1.0 - t) + other as f64 * t).round() as $ty
+            }
+        }
+    };
+}
+
+lerp_float!(f32);
+lerp_float!(f64);
+lerp_int!(i16);
+lerp_int!(i32);
+lerp_int!(u16);
+
+/// A [ReadBuf] adapter which resamples a source buffer from one sample rate
+/// to another using linear interpolation.
+///
+/// This is cheap and allocation-free in the steady state, which makes it
+/// suitable for bridging a source to a device whose sample rate differs, at
+/// the cost of some high-frequency aliasing. A higher-quality windowed-sinc
+/// kernel can be added later behind its own constructor (e.g.
+/// `Resample::with_sinc_kernel`) without changing this type's public shape.
+///
+/// # Examples
+///
+/// ```rust
+/// use rotary::io;
+/// use rotary::ReadBuf as _;
+///
+/// let from = rotary::interleaved![[0i16, 10, 20, 30]; 1];
+/// let from = io::Read::new(from);
+/// let resample = io::Resample::new(from, 1, 2);
+///
+/// assert_eq!(resample.remaining(), 8);
+/// ```
+pub struct Resample<B, T> {
+    buf: B,
+    source_hz: u32,
+    target_hz: u32,
+    /// Fractional read position, expressed in source frames.
+    pos: f64,
+    /// Interpolated samples for each output channel at the current read
+    /// position, indexed by channel number. This is recomputed in full on
+    /// construction and after every [advance][ReadBuf::advance], so
+    /// `channel()` only ever needs a plain borrow: unlike computing it
+    /// lazily through `&self`, there is no interior mutation for a
+    /// re-entrant call on the same channel to race with.
+    scratch: Vec<Vec<T>>,
+}
+
+impl<B, T> Resample<B, T>
+where
+    B: Buf,
+{
+    /// Access the underlying buffer.
+    pub fn as_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Access the underlying buffer mutably.
+    ///
+    /// Note that mutating the buffer this way (e.g. calling `advance`
+    /// directly on it) bypasses `Resample`'s own bookkeeping, so `channel()`
+    /// will keep returning samples for the read position as of the last
+    /// call to [Resample::new] or [ReadBuf::advance] on `self` until one of
+    /// those runs again.
+    pub fn as_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Convert into the underlying buffer.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    fn rate_ratio(&self) -> f64 {
+        self.source_hz as f64 / self.target_hz as f64
+    }
+}
+
+impl<B, T> Resample<B, T>
+where
+    B: Channels<T> + ReadBuf,
+    T: Lerp + Default,
+{
+    /// Construct a new resampling adapter converting `buf` from `source_hz`
+    /// to `target_hz` using linear interpolation.
+    pub fn new(buf: B, source_hz: u32, target_hz: u32) -> Self {
+        let scratch = (0..buf.channels()).map(|_| Vec::new()).collect();
+
+        let mut this = Self {
+            buf,
+            source_hz,
+            target_hz,
+            pos: 0.0,
+            scratch,
+        };
+
+        this.recompute();
+        this
+    }
+
+    /// Fill `scratch` with the interpolated samples for every output
+    /// channel at the current `pos`.
+    fn recompute(&mut self) {
+        let n = self.remaining();
+        let ratio = self.rate_ratio();
+
+        for channel in 0..self.scratch.len() {
+            let source = self.buf.channel(channel);
+            let available = source.len();
+
+            let scratch = &mut self.scratch[channel];
+            scratch.clear();
+            scratch.reserve(n);
+
+            for frame in 0..n {
+                let src = self.pos + frame as f64 * ratio;
+                let idx = src.floor() as usize;
+                let frac = src - idx as f64;
+
+                let a = source.get(idx).unwrap_or_default();
+                let b = source.get((idx + 1).min(available.saturating_sub(1))).unwrap_or_default();
+
+                scratch.push(a.lerp(b, frac));
+            }
+        }
+    }
+}
+
+impl<B, T> ReadBuf for Resample<B, T>
+where
+    B: Channels<T> + ReadBuf,
+    T: Lerp + Default,
+{
+    fn remaining(&self) -> usize {
+        let input_remaining = self.buf.remaining() as f64 - self.pos;
+        let remaining = input_remaining * self.target_hz as f64 / self.source_hz as f64;
+        remaining.floor().max(0.0) as usize
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.pos += n as f64 * self.rate_ratio();
+
+        // Consume whole input frames that can no longer be referenced by
+        // the interpolation window, keeping the fractional remainder so
+        // playback position stays continuous across calls.
+        let whole = self.pos.floor() as usize;
+
+        if whole > 0 {
+            self.buf.advance(whole);
+            self.pos -= whole as f64;
+        }
+
+        self.recompute();
+    }
+}
+
+impl<B, T> Buf for Resample<B, T>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+impl<B, T> Channels<T> for Resample<B, T>
+where
+    B: Channels<T> + ReadBuf,
+    T: Lerp + Default,
+{
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        Channel::Linear(&self.scratch[channel])
+    }
+}
@@ -0,0 +1,255 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use rotary_core::{Buf, Channel, Channels, ReadBuf};
+
+/// A lock-free, single-producer single-consumer ring buffer of interleaved
+/// audio frames.
+///
+/// This is intended for the real-time boundary between an application thread
+/// producing frames and a device callback consuming them, where neither side
+/// may block or allocate. Construct one with [RingBuffer::new] and split it
+/// into a [Producer] and [Consumer] with [RingBuffer::split].
+///
+/// # Examples
+///
+/// ```rust
+/// use rotary::io;
+/// use rotary::ReadBuf as _;
+///
+/// let (mut producer, mut consumer) = io::RingBuffer::<i16>::new(4, 2).split();
+///
+/// assert_eq!(consumer.remaining(), 0);
+///
+/// let from = rotary::interleaved![[1, 2, 3, 4]; 2];
+/// assert_eq!(producer.write(&from), 4);
+/// assert_eq!(consumer.remaining(), 4);
+/// ```
+///
+/// `remaining()` only ever reports the contiguous run up to the wrap
+/// boundary, matching what `channel()` can actually hand back; a consumer
+/// that has wrapped needs a follow-up `advance` to see the rest. `write`
+/// has the same limitation on the producer side: it never spans the wrap
+/// boundary in a single call either.
+///
+/// ```rust
+/// use rotary::io;
+/// use rotary::ReadBuf as _;
+///
+/// let (mut producer, mut consumer) = io::RingBuffer::<i16>::new(4, 1).split();
+///
+/// let from = rotary::interleaved![[1, 2, 3]; 1];
+/// assert_eq!(producer.write(&from), 3);
+/// consumer.advance(3);
+///
+/// // Only one slot is contiguous before position 4 wraps back to 0, even
+/// // though four frames of free space exist in total.
+/// let from = rotary::interleaved![[4, 5, 6]; 1];
+/// assert_eq!(producer.write(&from), 1);
+/// assert_eq!(consumer.remaining(), 1);
+/// consumer.advance(1);
+///
+/// // A follow-up call starts after the wrap. `write` always reads `from`
+/// // from its first frame, so all three of its frames are written again.
+/// assert_eq!(producer.write(&from), 3);
+/// assert_eq!(consumer.remaining(), 3);
+/// ```
+pub struct RingBuffer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> RingBuffer<T>
+where
+    T: Copy + Default,
+{
+    /// Construct a new ring buffer with room for `capacity` frames of
+    /// `channels` channels each.
+    pub fn new(capacity: usize, channels: usize) -> Self {
+        let data = vec![T::default(); capacity * channels].into_boxed_slice();
+
+        Self {
+            shared: Arc::new(Shared {
+                data: UnsafeCell::new(data),
+                capacity,
+                channels,
+                head: AtomicUsize::new(0),
+                tail: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Split the ring buffer into its producer and consumer halves.
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        (
+            Producer {
+                shared: self.shared.clone(),
+            },
+            Consumer {
+                shared: self.shared,
+            },
+        )
+    }
+}
+
+struct Shared<T> {
+    data: UnsafeCell<Box<[T]>>,
+    capacity: usize,
+    channels: usize,
+    // Monotonically increasing frame counters. The number of frames
+    // currently buffered is `tail - head` and the position of a frame in the
+    // backing store is obtained by reducing its counter modulo `capacity`.
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// SAFETY: `Shared` is only ever mutated through the disjoint, non-overlapping
+// regions owned by the producer and the consumer respectively, which is
+// enforced by the head/tail cursor protocol below.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn len(&self) -> usize {
+        self.tail.load(Ordering::Acquire) - self.head.load(Ordering::Acquire)
+    }
+
+    /// The contiguous run of frames available for reading starting at
+    /// `head`, clamped so that it never wraps around the end of the backing
+    /// store.
+    fn readable_run(&self) -> (usize, usize) {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        let len = tail - head;
+
+        let start = head % self.capacity;
+        let run = len.min(self.capacity - start);
+        (start, run)
+    }
+
+    /// The contiguous run of frames available for writing starting at
+    /// `tail`, clamped so that it never wraps around the end of the backing
+    /// store.
+    fn writable_run(&self) -> (usize, usize) {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        let free = self.capacity - (tail - head);
+
+        let start = tail % self.capacity;
+        let run = free.min(self.capacity - start);
+        (start, run)
+    }
+}
+
+/// The producer half of a [RingBuffer], which writes frames into the shared
+/// backing store.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Producer<T>
+where
+    T: Copy,
+{
+    /// The number of frames that can currently be written without
+    /// overwriting unread data.
+    pub fn remaining(&self) -> usize {
+        self.shared.capacity - self.shared.len()
+    }
+
+    /// Write as many frames as possible from `buf` into the ring buffer,
+    /// returning the number of frames written.
+    ///
+    /// Like [Consumer]'s read side, this only ever fills the contiguous run
+    /// up to the wrap boundary: it may return fewer frames than `buf` holds
+    /// even when more free space exists in total, and the caller is
+    /// expected to make a follow-up call to place the rest once the
+    /// backing store has wrapped.
+    pub fn write<B>(&mut self, buf: &B) -> usize
+    where
+        B: Buf + Channels<T>,
+    {
+        let (start, run) = self.shared.writable_run();
+        let n = run.min(buf.frames_hint().unwrap_or(run));
+        let channels = self.shared.channels;
+
+        // SAFETY: `writable_run` only ever describes the region between
+        // `tail` and `head`, which the consumer does not touch until we
+        // advance `tail` below.
+        let data = unsafe { &mut *self.shared.data.get() };
+
+        for channel in 0..channels.min(buf.channels()) {
+            let source = buf.channel(channel);
+
+            for frame in 0..n {
+                data[(start + frame) * channels + channel] = source[frame];
+            }
+        }
+
+        self.shared.tail.fetch_add(n, Ordering::Release);
+        n
+    }
+}
+
+/// The consumer half of a [RingBuffer], which reads frames out of the shared
+/// backing store.
+///
+/// This implements [ReadBuf] and [Channels], so it can be driven directly
+/// with [crate::io::copy_remaining].
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Consumer<T> {
+    /// Advance the consumer by `n` frames, marking them as read.
+    pub fn advance(&mut self, n: usize) {
+        self.shared.head.fetch_add(n, Ordering::Release);
+    }
+}
+
+impl<T> ReadBuf for Consumer<T> {
+    // Clamped to the contiguous run `channel()` can actually return, *not*
+    // the total unread length across the whole ring. The two would
+    // disagree once the buffer has wrapped, since `channel()` never spans
+    // the wrap boundary; callers drain the rest with a follow-up
+    // `copy`/`advance` call once this run has been consumed.
+    fn remaining(&self) -> usize {
+        self.shared.readable_run().1
+    }
+
+    fn advance(&mut self, n: usize) {
+        Consumer::advance(self, n);
+    }
+}
+
+impl<T> Buf for Consumer<T> {
+    fn frames_hint(&self) -> Option<usize> {
+        Some(self.shared.readable_run().1)
+    }
+
+    fn channels(&self) -> usize {
+        self.shared.channels
+    }
+}
+
+impl<T> Channels<T> for Consumer<T>
+where
+    T: Copy,
+{
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        let (start, run) = self.shared.readable_run();
+        let channels = self.shared.channels;
+
+        // SAFETY: `readable_run` only ever describes the region between
+        // `head` and `tail`, which the producer does not touch until we
+        // advance `head`.
+        let data = unsafe { &*self.shared.data.get() };
+        let base = start * channels;
+
+        Channel::Interleaved {
+            buf: &data[base..base + run * channels],
+            channels,
+            channel,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use rotary_core::{Channels, ChannelsMut, ReadBuf, WriteBuf};
+
+/// A 24-bit sample, sign-extended into the low bits of an `i32`.
+///
+/// 24-bit PCM has no native Rust integer type, and its normalization
+/// factor (`2^23`) differs from plain 32-bit PCM's (`2^31`), so it needs a
+/// distinct type to carry its own [Translate] impls rather than overloading
+/// `i32`. This is the representation [crate::wav] decodes 24-bit samples
+/// into.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct I24(pub i32);
+
+/// Conversion between two sample formats, using the standard normalization
+/// for each pair so mixed-format pipelines (e.g. a `f32` mixing graph
+/// feeding an `i16` device buffer) don't need a manual pre-conversion pass.
+pub trait Translate<To> {
+    /// Translate `self` into the `To` sample format.
+    fn translate(self) -> To;
+}
+
+impl Translate<f32> for i16 {
+    fn translate(self) -> f32 {
+        self as f32 / 32768.0
+    }
+}
+
+impl Translate<i16> for f32 {
+    fn translate(self) -> i16 {
+        (self.clamp(-1.0, 1.0) * 32767.0).round() as i16
+    }
+}
+
+impl Translate<f32> for i32 {
+    fn translate(self) -> f32 {
+        self as f32 / 2147483648.0
+    }
+}
+
+impl Translate<i32> for f32 {
+    fn translate(self) -> i32 {
+        (self.clamp(-1.0, 1.0) * 2147483647.0).round() as i32
+    }
+}
+
+impl Translate<i16> for u16 {
+    fn translate(self) -> i16 {
+        (self as i32 - 32768) as i16
+    }
+}
+
+impl Translate<u16> for i16 {
+    fn translate(self) -> u16 {
+        (self as i32 + 32768) as u16
+    }
+}
+
+impl Translate<f32> for I24 {
+    fn translate(self) -> f32 {
+        self.0 as f32 / 8_388_608.0
+    }
+}
+
+impl Translate<I24> for f32 {
+    fn translate(self) -> I24 {
+        I24((self.clamp(-1.0, 1.0) * 8_388_607.0).round() as i32)
+    }
+}
+
+impl<T> Translate<T> for T
+where
+    T: Copy,
+{
+    fn translate(self) -> T {
+        self
+    }
+}
+
+/// Like [super::copy_remaining], but translates each sample from `from`'s
+/// format into `to`'s format as it copies, using [Translate].
+///
+/// Copies as many frames as are available in both `from` and `to`,
+/// advancing each by the number of frames actually transferred.
+///
+/// # Examples
+///
+/// ```rust
+/// use rotary::io;
+///
+/// let from = rotary::interleaved![[0.0f32, 0.5, -1.0, 1.0]; 2];
+/// let mut from = io::Read::new(from);
+///
+/// let to = rotary::interleaved![[0i16; 4]; 2];
+/// let mut to = io::ReadWrite::new(to);
+///
+/// io::copy_remaining_convert(&mut from, &mut to);
+///
+/// assert_eq!(to.as_ref().as_slice(), &[0, 0, 16384, 16384, -32767, -32767, 32767, 32767]);
+/// ```
+pub fn copy_remaining_convert<From, To, FromT, ToT>(from: &mut From, to: &mut To)
+where
+    From: ReadBuf + Channels<FromT>,
+    To: WriteBuf + ChannelsMut<ToT>,
+    FromT: Translate<ToT> + Copy,
+    ToT: Copy,
+{
+    let n = usize::min(from.remaining(), to.remaining_mut());
+    let channels = usize::min(from.channels(), to.channels());
+
+    for channel in 0..channels {
+        let source = from.channel(channel);
+        let mut dest = to.channel_mut(channel);
+
+        for frame in 0..n {
+            dest[frame] = source[frame].translate();
+        }
+    }
+
+    from.advance(n);
+    to.advance_mut(n);
+}
@@ -0,0 +1,13 @@
+//! rotary is a library for working with structured audio buffers, with a
+//! focus on adapters that can be composed to bridge sources and sinks with
+//! differing topologies, sample rates, and sample formats.
+//!
+//! The core buffer and channel traits live in [rotary_core] and are
+//! re-exported here; this crate adds the higher-level [io] adapters and
+//! [wav] file support built on top of them.
+
+#[doc(inline)]
+pub use rotary_core::*;
+
+pub mod io;
+pub mod wav;
@@ -0,0 +1,48 @@
+//! Minimal RIFF/WAVE read and write support producing and consuming
+//! [rotary] buffers directly, so audio files can be spliced into the same
+//! adapter pipeline as any other [Channels] source.
+//!
+//! [rotary]: crate
+//! [Channels]: rotary_core::Channels
+
+mod reader;
+pub use self::reader::{reader, Header, SampleFormat, Samples};
+
+mod writer;
+pub use self::writer::{writer, WavSample};
+
+use std::fmt;
+use std::io;
+
+/// Errors that can occur while reading or writing a WAV stream.
+#[derive(Debug)]
+pub enum Error {
+    /// An underlying I/O error.
+    Io(io::Error),
+    /// The stream did not start with a `RIFF`/`WAVE` header.
+    NotRiffWave,
+    /// The `fmt ` chunk was missing, truncated, or described an
+    /// unsupported format.
+    BadFormat(&'static str),
+    /// No `data` chunk was found before the end of the stream.
+    MissingDataChunk,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::NotRiffWave => write!(f, "not a RIFF/WAVE stream"),
+            Error::BadFormat(reason) => write!(f, "bad `fmt ` chunk: {}", reason),
+            Error::MissingDataChunk => write!(f, "missing `data` chunk"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
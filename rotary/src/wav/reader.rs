@@ -0,0 +1,242 @@
+use std::io::Read as _;
+
+use rotary_core::Interleaved;
+
+use crate::io;
+use crate::wav::Error;
+
+const RIFF: [u8; 4] = *b"RIFF";
+const WAVE: [u8; 4] = *b"WAVE";
+const FMT: [u8; 4] = *b"fmt ";
+const DATA: [u8; 4] = *b"data";
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// The sample format described by a WAV stream's `fmt ` chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleFormat {
+    /// Integer PCM, at the associated bit depth.
+    Pcm,
+    /// IEEE 754 floating point.
+    Float,
+}
+
+/// The decoded `fmt ` chunk of a WAV stream.
+#[derive(Debug, Clone, Copy)]
+pub struct Header {
+    /// Number of interleaved channels.
+    pub channels: u16,
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Bits per sample, as stored in the file (8, 16, 24 or 32).
+    pub bits_per_sample: u16,
+    /// Whether samples are integer PCM or IEEE float.
+    pub format: SampleFormat,
+}
+
+/// The decoded sample data of a WAV stream, typed according to its
+/// `bits_per_sample` and [SampleFormat].
+///
+/// 24-bit PCM is unpacked into sign-extended [io::I24] samples, which also
+/// carries its own [io::Translate] impls since its normalization differs
+/// from plain 32-bit PCM.
+pub enum Samples {
+    /// 8-bit unsigned PCM.
+    I8(io::Read<Interleaved<u8>>),
+    /// 16-bit signed PCM.
+    I16(io::Read<Interleaved<i16>>),
+    /// 24-bit signed PCM, sign-extended into [io::I24].
+    I24(io::Read<Interleaved<io::I24>>),
+    /// 32-bit signed PCM.
+    I32(io::Read<Interleaved<i32>>),
+    /// 32-bit IEEE float.
+    F32(io::Read<Interleaved<f32>>),
+}
+
+/// Parse a RIFF/WAVE stream, returning its [Header] together with the
+/// decoded [Samples].
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> Result<(), rotary::wav::Error> {
+/// use std::io::Cursor;
+///
+/// let buf = rotary::interleaved![[0i16, 1, -1, 32767]; 2];
+/// let mut file = Cursor::new(Vec::new());
+/// rotary::wav::writer(&mut file, &buf, 44_100)?;
+///
+/// let (header, samples) = rotary::wav::reader(Cursor::new(file.into_inner()))?;
+/// assert_eq!(header.channels, 2);
+/// assert_eq!(header.sample_rate, 44_100);
+///
+/// let samples = match samples {
+///     rotary::wav::Samples::I16(samples) => samples,
+///     _ => panic!("expected 16-bit PCM"),
+/// };
+/// assert_eq!(samples.as_ref().as_slice(), &[0, 0, 1, 1, -1, -1, 32767, 32767]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn reader<R>(mut input: R) -> Result<(Header, Samples), Error>
+where
+    R: std::io::Read,
+{
+    let mut riff_header = [0u8; 12];
+    input.read_exact(&mut riff_header)?;
+
+    if riff_header[0..4] != RIFF || riff_header[8..12] != WAVE {
+        return Err(Error::NotRiffWave);
+    }
+
+    let mut header = None;
+    let mut data = None;
+
+    loop {
+        let mut id = [0u8; 4];
+
+        if input.read_exact(&mut id).is_err() {
+            break;
+        }
+
+        let size = read_u32(&mut input)?;
+
+        if id == FMT {
+            header = Some(read_fmt_chunk(&mut input, size)?);
+            skip_pad(&mut input, size)?;
+        } else if id == DATA {
+            let mut bytes = vec![0u8; size as usize];
+            input.read_exact(&mut bytes)?;
+            data = Some(bytes);
+            break;
+        } else {
+            skip(&mut input, size)?;
+        }
+    }
+
+    let header = header.ok_or(Error::BadFormat("missing `fmt ` chunk"))?;
+    let data = data.ok_or(Error::MissingDataChunk)?;
+
+    let samples = decode(&header, data)?;
+    Ok((header, samples))
+}
+
+fn read_fmt_chunk<R>(input: &mut R, size: u32) -> Result<Header, Error>
+where
+    R: std::io::Read,
+{
+    let mut chunk = vec![0u8; size as usize];
+    input.read_exact(&mut chunk)?;
+
+    if chunk.len() < 16 {
+        return Err(Error::BadFormat("`fmt ` chunk shorter than 16 bytes"));
+    }
+
+    let tag = u16::from_le_bytes([chunk[0], chunk[1]]);
+    let channels = u16::from_le_bytes([chunk[2], chunk[3]]);
+    let sample_rate = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+    let bits_per_sample = u16::from_le_bytes([chunk[14], chunk[15]]);
+
+    let format = match tag {
+        FORMAT_PCM => SampleFormat::Pcm,
+        FORMAT_IEEE_FLOAT => SampleFormat::Float,
+        _ => return Err(Error::BadFormat("unsupported format tag")),
+    };
+
+    Ok(Header {
+        channels,
+        sample_rate,
+        bits_per_sample,
+        format,
+    })
+}
+
+fn decode(header: &Header, data: Vec<u8>) -> Result<Samples, Error> {
+    let channels = header.channels as usize;
+
+    let samples = match (header.format, header.bits_per_sample) {
+        (SampleFormat::Pcm, 8) => {
+            Samples::I8(io::Read::new(Interleaved::new(data, channels)))
+        }
+        (SampleFormat::Pcm, 16) => {
+            let samples = data
+                .chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]]))
+                .collect();
+            Samples::I16(io::Read::new(Interleaved::new(samples, channels)))
+        }
+        (SampleFormat::Pcm, 24) => {
+            let samples = data
+                .chunks_exact(3)
+                .map(|b| io::I24(sign_extend_24(b[0], b[1], b[2])))
+                .collect();
+            Samples::I24(io::Read::new(Interleaved::new(samples, channels)))
+        }
+        (SampleFormat::Pcm, 32) => {
+            let samples = data
+                .chunks_exact(4)
+                .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+            Samples::I32(io::Read::new(Interleaved::new(samples, channels)))
+        }
+        (SampleFormat::Float, 32) => {
+            let samples = data
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+            Samples::F32(io::Read::new(Interleaved::new(samples, channels)))
+        }
+        _ => return Err(Error::BadFormat("unsupported bit depth")),
+    };
+
+    Ok(samples)
+}
+
+/// Unpack a little-endian 24-bit packed sample into a sign-extended `i32`.
+fn sign_extend_24(b0: u8, b1: u8, b2: u8) -> i32 {
+    let unsigned = u32::from_le_bytes([b0, b1, b2, 0]);
+    // Shift the 24-bit value into the top of a 32-bit word and arithmetic
+    // shift it back down so the sign bit is correctly propagated.
+    ((unsigned << 8) as i32) >> 8
+}
+
+fn read_u32<R>(input: &mut R) -> Result<u32, Error>
+where
+    R: std::io::Read,
+{
+    let mut bytes = [0u8; 4];
+    input.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn skip<R>(input: &mut R, size: u32) -> Result<(), Error>
+where
+    R: std::io::Read,
+{
+    let mut remaining = size as u64;
+    let mut buf = [0u8; 4096];
+
+    while remaining > 0 {
+        let n = (remaining as usize).min(buf.len());
+        input.read_exact(&mut buf[..n])?;
+        remaining -= n as u64;
+    }
+
+    skip_pad(input, size)
+}
+
+/// RIFF chunks are word-aligned: a chunk with an odd `size` is followed by
+/// one byte of padding that isn't counted in `size`. Consume it so the next
+/// chunk ID read lands on the correct offset.
+fn skip_pad<R>(input: &mut R, size: u32) -> Result<(), Error>
+where
+    R: std::io::Read,
+{
+    if size % 2 == 1 {
+        let mut pad = [0u8; 1];
+        input.read_exact(&mut pad)?;
+    }
+
+    Ok(())
+}